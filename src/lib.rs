@@ -7,7 +7,7 @@
 //! use futures::{
 //!     future::{self, FutureObj},
 //! };
-//! use http_service::{HttpService, Response};
+//! use http_service::{ConnectionInfo, HttpService, Response};
 //! use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 //! 
 //! struct Server {
@@ -32,7 +32,7 @@
 //!    type ConnectionFuture = future::Ready<Result<(), std::io::Error>>;
 //!     type Fut = FutureObj<'static, Result<http_service::Response, std::io::Error>>;
 //!     
-//!     fn connect(&self) -> Self::ConnectionFuture {
+//!     fn connect(&self, _info: &ConnectionInfo) -> Self::ConnectionFuture {
 //!         future::ok(())
 //!     }
 //! 
@@ -60,7 +60,8 @@
 
 use bytes::Bytes;
 use futures::{
-    future,
+    future::{self, FutureObj},
+    io::{AsyncRead, AsyncWrite},
     prelude::*,
     stream::{self, StreamObj},
     task::Context,
@@ -68,7 +69,14 @@ use futures::{
 };
 
 use std::marker::Unpin;
+use std::net::SocketAddr;
 use std::pin::Pin;
+use std::sync::Mutex;
+
+#[cfg(feature = "tower")]
+mod tower;
+#[cfg(feature = "tower")]
+pub use crate::tower::{FromTower, TowerCompat, TowerCompatError};
 
 /// The raw body of an http request or response.
 ///
@@ -78,24 +86,54 @@ use std::pin::Pin;
 #[derive(Debug)]
 pub struct Body {
     stream: StreamObj<'static, Result<Bytes, std::io::Error>>,
+    size_hint: Option<u64>,
 }
 
 impl Body {
     /// Create an empty body.
     pub fn empty() -> Self {
-        Body::from_stream(stream::empty())
+        Body::from_stream_with_len(stream::empty(), 0)
     }
 
-    /// Create a body from a stream of `Bytes`
+    /// Create a body from a stream of `Bytes`, with no known total length.
     pub fn from_stream<S>(s: S) -> Self
     where
         S: Stream<Item = Result<Bytes, std::io::Error>> + Send + 'static,
     {
         Self {
             stream: StreamObj::new(Box::new(s)),
+            size_hint: None,
+        }
+    }
+
+    /// Create a body from a stream of `Bytes`, with a known total length in bytes.
+    ///
+    /// Backends can use the length reported by [`Body::len`] to set a precise
+    /// `Content-Length` header instead of falling back to chunked encoding.
+    pub fn from_stream_with_len<S>(s: S, len: u64) -> Self
+    where
+        S: Stream<Item = Result<Bytes, std::io::Error>> + Send + 'static,
+    {
+        Self {
+            stream: StreamObj::new(Box::new(s)),
+            size_hint: Some(len),
         }
     }
 
+    /// The exact size of this body's contents in bytes, if known.
+    ///
+    /// This is `Some` for bodies built from an in-memory buffer (e.g.
+    /// `Body::from(vec)`) or via [`Body::from_stream_with_len`], and `None` for
+    /// bodies built from an arbitrary stream via [`Body::from_stream`].
+    pub fn len(&self) -> Option<u64> {
+        self.size_hint
+    }
+
+    /// Returns `true` if this body is known to be empty.
+    pub fn is_empty(&self) -> bool {
+        self.size_hint == Some(0)
+    }
+
     /// Reads the stream into a new `Vec`.
     pub async fn into_vec(mut self) -> std::io::Result<Vec<u8>> {
         let mut bytes = Vec::new();
@@ -104,11 +142,60 @@ impl Body {
         }
         Ok(bytes)
     }
+
+    /// Reads the stream into a new `Vec`, failing once the accumulated size
+    /// exceeds `max` bytes.
+    ///
+    /// This guards against unbounded memory growth when reading a body (such
+    /// as a request body) whose size isn't otherwise bounded.
+    pub async fn into_vec_limited(mut self, max: usize) -> std::io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        while let Some(chunk) = await!(self.next()) {
+            let chunk = chunk?;
+            if bytes.len() + chunk.len() > max {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("body exceeded limit of {} bytes", max),
+                ));
+            }
+            bytes.extend(chunk);
+        }
+        Ok(bytes)
+    }
+
+    /// Reads the stream into a `String`, failing if the bytes are not valid UTF-8.
+    pub async fn into_string(self) -> std::io::Result<String> {
+        let bytes = await!(self.into_vec())?;
+        String::from_utf8(bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Wrap this body so its contents are compressed on the fly with `encoding`.
+    ///
+    /// Each chunk read from the underlying stream is fed into the compressor as
+    /// it arrives, so large or unbounded bodies can be compressed without
+    /// buffering the whole payload in memory. Services typically pick `encoding`
+    /// from the request's `Accept-Encoding` header and set `Content-Encoding` on
+    /// the response to match. Since the compressed size can't generally be known
+    /// up front, the resulting body's [`Body::len`] is `None`.
+    pub fn compressed(self, encoding: Encoding) -> Self {
+        Self {
+            stream: StreamObj::new(Box::new(CompressedBody {
+                inner: self,
+                encoder: Some(Encoder::new(encoding)),
+            })),
+            size_hint: None,
+        }
+    }
 }
 
 impl<T: Into<Bytes> + Send> From<T> for Body {
     fn from(x: T) -> Self {
-        Self::from_stream(stream::once(future::ok(x.into())))
+        let bytes = x.into();
+        let len = bytes.len() as u64;
+        Self {
+            stream: StreamObj::new(Box::new(stream::once(future::ok(bytes)))),
+            size_hint: Some(len),
+        }
     }
 }
 
@@ -121,12 +208,280 @@ impl Stream for Body {
     }
 }
 
+/// A content-coding that [`Body::compressed`] can apply to a body's stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// The `gzip` content-coding.
+    Gzip,
+    /// The `br` (Brotli) content-coding.
+    Brotli,
+    /// The `deflate` content-coding.
+    Deflate,
+}
+
+/// The streaming compressor state backing a given [`Encoding`].
+///
+/// Each variant writes incoming chunks into the corresponding encoder and
+/// drains whatever compressed output the encoder has buffered so far.
+enum Encoder {
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+    Deflate(flate2::write::DeflateEncoder<Vec<u8>>),
+    Brotli(brotli::CompressorWriter<Vec<u8>>),
+}
+
+impl Encoder {
+    fn new(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Gzip => Encoder::Gzip(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            )),
+            Encoding::Deflate => Encoder::Deflate(flate2::write::DeflateEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            )),
+            Encoding::Brotli => Encoder::Brotli(brotli::CompressorWriter::new(Vec::new(), 4096, 11, 22)),
+        }
+    }
+
+    fn write(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        use std::io::Write;
+        match self {
+            Encoder::Gzip(w) => w.write_all(chunk),
+            Encoder::Deflate(w) => w.write_all(chunk),
+            Encoder::Brotli(w) => w.write_all(chunk),
+        }
+    }
+
+    /// Take whatever compressed output the encoder has produced so far,
+    /// leaving its internal buffer empty.
+    fn take_output(&mut self) -> Bytes {
+        let buf = match self {
+            Encoder::Gzip(w) => w.get_mut(),
+            Encoder::Deflate(w) => w.get_mut(),
+            Encoder::Brotli(w) => w.get_mut(),
+        };
+        Bytes::from(std::mem::replace(buf, Vec::new()))
+    }
+
+    /// Finalise the stream, returning any trailing bytes (checksums, footers).
+    fn finish(self) -> std::io::Result<Bytes> {
+        match self {
+            Encoder::Gzip(mut w) => {
+                w.try_finish()?;
+                Ok(Bytes::from(std::mem::replace(w.get_mut(), Vec::new())))
+            }
+            Encoder::Deflate(mut w) => {
+                w.try_finish()?;
+                Ok(Bytes::from(std::mem::replace(w.get_mut(), Vec::new())))
+            }
+            // `CompressorWriter` only issues the stream-terminating
+            // `BROTLI_OPERATION_FINISH` from `into_inner`/`Drop`; a plain `flush`
+            // leaves the final meta-block unwritten, so consume it here instead
+            // of draining through `take_output`.
+            Encoder::Brotli(w) => Ok(Bytes::from(w.into_inner())),
+        }
+    }
+}
+
+/// The [`Stream`] adapter returned by [`Body::compressed`].
+struct CompressedBody {
+    inner: Body,
+    encoder: Option<Encoder>,
+}
+
+impl Unpin for CompressedBody {}
+
+impl Stream for CompressedBody {
+    type Item = Result<Bytes, std::io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let this = &mut *self;
+            let encoder = match this.encoder.as_mut() {
+                Some(encoder) => encoder,
+                None => return Poll::Ready(None),
+            };
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    if let Err(e) = encoder.write(&chunk) {
+                        this.encoder = None;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    let out = encoder.take_output();
+                    if !out.is_empty() {
+                        return Poll::Ready(Some(Ok(out)));
+                    }
+                    // The encoder buffered this chunk without emitting output yet;
+                    // pull more input before yielding to the caller.
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.encoder = None;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    let encoder = this.encoder.take().unwrap();
+                    return match encoder.finish() {
+                        Ok(trailer) if !trailer.is_empty() => Poll::Ready(Some(Ok(trailer))),
+                        Ok(_) => Poll::Ready(None),
+                        Err(e) => Poll::Ready(Some(Err(e))),
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
 /// An HTTP request with a streaming body.
 pub type Request = http::Request<Body>;
 
 /// An HTTP response with a streaming body.
 pub type Response = http::Response<Body>;
 
+/// The raw, bidirectional byte stream left behind once a connection has been
+/// taken over from HTTP, for example after responding with `101 Switching
+/// Protocols`.
+///
+/// Any bytes the backend had already read from the socket before recognising
+/// the upgrade (such as the start of a client's WebSocket frame) are returned
+/// first, ahead of whatever the backend's transport yields afterwards.
+#[derive(Debug)]
+pub struct Upgraded {
+    pending: Bytes,
+    io: Pin<Box<dyn AsyncReadWrite>>,
+}
+
+impl Upgraded {
+    /// Wrap a raw duplex connection, along with any bytes already read from it,
+    /// as an `Upgraded` handle.
+    pub fn new<T>(io: T, pending: Bytes) -> Self
+    where
+        T: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    {
+        Self {
+            pending,
+            io: Pin::new(Box::new(io)),
+        }
+    }
+}
+
+impl Unpin for Upgraded {}
+
+impl AsyncRead for Upgraded {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        if !self.pending.is_empty() {
+            let len = std::cmp::min(buf.len(), self.pending.len());
+            buf[..len].copy_from_slice(&self.pending.split_to(len));
+            return Poll::Ready(Ok(len));
+        }
+        self.io.as_mut().poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for Upgraded {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.io.as_mut().poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.io.as_mut().poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.io.as_mut().poll_close(cx)
+    }
+}
+
+trait AsyncReadWrite: AsyncRead + AsyncWrite + Send + Unpin {}
+impl<T: AsyncRead + AsyncWrite + Send + Unpin + ?Sized> AsyncReadWrite for T {}
+
+impl std::fmt::Debug for dyn AsyncReadWrite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncReadWrite").finish()
+    }
+}
+
+/// A callback invoked with the raw connection once a response carrying it has
+/// been flushed to the client and the protocol has switched away from HTTP.
+type UpgradeCallback = Box<dyn FnOnce(Upgraded) -> FutureObj<'static, std::io::Result<()>> + Send>;
+
+/// The response-extension value produced by [`ResponseExt::on_upgrade`].
+///
+/// The callback itself is only `Send` (it's an `FnOnce`), but `http::Extensions`
+/// requires stored values to be `Sync` as well, so it's kept behind a `Mutex`.
+/// Backends take it out with [`OnUpgrade::take`] once the `101` response has
+/// been flushed.
+pub struct OnUpgrade(Mutex<Option<UpgradeCallback>>);
+
+impl OnUpgrade {
+    /// Take the upgrade callback out, if one is present and hasn't already been
+    /// taken by another caller.
+    pub fn take(&self) -> Option<impl FnOnce(Upgraded) -> FutureObj<'static, std::io::Result<()>>> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl std::fmt::Debug for OnUpgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnUpgrade").finish()
+    }
+}
+
+/// Extension methods on [`Response`] for attaching an HTTP upgrade handler.
+///
+/// A service that intends to switch protocols (for example to hand a connection
+/// off to a WebSocket implementation) builds a `101 Switching Protocols` response
+/// and calls [`on_upgrade`](ResponseExt::on_upgrade) on it. Once the backend has
+/// written that response to the client, it takes over the raw connection and
+/// invokes the callback with it.
+pub trait ResponseExt {
+    /// Attach a callback to be run with the raw connection once this response
+    /// has been flushed and the backend takes over the upgraded connection.
+    fn on_upgrade<F, Fut>(&mut self, upgrade: F)
+    where
+        F: FnOnce(Upgraded) -> Fut + Send + 'static,
+        Fut: Future<Output = std::io::Result<()>> + Send + 'static;
+}
+
+impl ResponseExt for Response {
+    fn on_upgrade<F, Fut>(&mut self, upgrade: F)
+    where
+        F: FnOnce(Upgraded) -> Fut + Send + 'static,
+        Fut: Future<Output = std::io::Result<()>> + Send + 'static,
+    {
+        let callback: UpgradeCallback = Box::new(move |upgraded| FutureObj::new(Box::new(upgrade(upgraded))));
+        self.extensions_mut()
+            .insert(OnUpgrade(Mutex::new(Some(callback))));
+    }
+}
+
+/// Metadata about the transport a connection was accepted on.
+///
+/// A server backend builds a `ConnectionInfo` when it accepts a new connection,
+/// before it knows anything about the requests that will arrive on it, and passes
+/// it to [`HttpService::connect`]. This lets a service inspect the remote peer,
+/// the local address it was accepted on, and (for TLS-terminating backends) any
+/// negotiated protocol or client certificate data carried in `extensions`.
+#[derive(Debug, Default)]
+pub struct ConnectionInfo {
+    /// The remote address of the peer, if the backend knows it.
+    pub peer_addr: Option<SocketAddr>,
+    /// The local address the connection was accepted on, if the backend knows it.
+    pub local_addr: Option<SocketAddr>,
+    /// Backend-specific data about the connection, such as the negotiated ALPN
+    /// protocol or a client certificate presented during a TLS handshake.
+    pub extensions: http::Extensions,
+}
+
 /// An async HTTP service
 ///
 /// An instance represents a service as a whole. The associated `Conn` type
@@ -150,8 +505,9 @@ pub trait HttpService: Send + Sync + 'static {
     /// Initiate a new connection.
     ///
     /// This method is given access to the global service (`&self`), which may provide
-    /// handles to connection pools, thread pools, or other global data.
-    fn connect(&self) -> Self::ConnectionFuture;
+    /// handles to connection pools, thread pools, or other global data, as well as
+    /// `info` describing the transport the connection was accepted on.
+    fn connect(&self, info: &ConnectionInfo) -> Self::ConnectionFuture;
 
     /// The async computation for producing the response.
     ///
@@ -175,7 +531,7 @@ where
 {
     type Connection = ();
     type ConnectionFuture = future::Ready<Result<(), Fut::Error>>;
-    fn connect(&self) -> Self::ConnectionFuture {
+    fn connect(&self, _info: &ConnectionInfo) -> Self::ConnectionFuture {
         future::ok(())
     }
 
@@ -184,3 +540,182 @@ where
         (self)(req)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::io::Read;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    const MESSAGE: &[u8] = b"hello, compressed world";
+
+    #[test]
+    fn empty_body_reports_a_zero_length() {
+        let body = Body::empty();
+        assert_eq!(body.len(), Some(0));
+        assert!(body.is_empty());
+    }
+
+    #[test]
+    fn from_buffer_reports_its_exact_length() {
+        let body = Body::from(MESSAGE);
+        assert_eq!(body.len(), Some(MESSAGE.len() as u64));
+        assert!(!body.is_empty());
+    }
+
+    #[test]
+    fn from_stream_has_no_length_hint() {
+        let body = Body::from_stream(stream::once(future::ok(Bytes::from_static(MESSAGE))));
+        assert_eq!(body.len(), None);
+        assert!(!body.is_empty());
+    }
+
+    #[test]
+    fn into_vec_limited_errors_once_the_body_exceeds_max() {
+        let body = Body::from(MESSAGE);
+        let err = block_on(body.into_vec_limited(MESSAGE.len() - 1)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn into_vec_limited_accepts_a_body_at_exactly_max() {
+        let body = Body::from(MESSAGE);
+        let bytes = block_on(body.into_vec_limited(MESSAGE.len())).unwrap();
+        assert_eq!(bytes, MESSAGE);
+    }
+
+    #[test]
+    fn compressed_gzip_round_trips() {
+        let compressed = block_on(Body::from(MESSAGE).compressed(Encoding::Gzip).into_vec()).unwrap();
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(&compressed[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, MESSAGE);
+    }
+
+    #[test]
+    fn compressed_deflate_round_trips() {
+        let compressed = block_on(Body::from(MESSAGE).compressed(Encoding::Deflate).into_vec()).unwrap();
+        let mut decompressed = Vec::new();
+        flate2::read::DeflateDecoder::new(&compressed[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, MESSAGE);
+    }
+
+    #[test]
+    fn compressed_brotli_round_trips() {
+        let compressed = block_on(Body::from(MESSAGE).compressed(Encoding::Brotli).into_vec()).unwrap();
+        let mut decompressed = Vec::new();
+        brotli::Decompressor::new(&compressed[..], 4096)
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, MESSAGE);
+    }
+
+    #[derive(Debug)]
+    struct NullIo;
+
+    impl AsyncRead for NullIo {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            _buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            Poll::Ready(Ok(0))
+        }
+    }
+
+    impl AsyncWrite for NullIo {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn upgraded_yields_pending_bytes_before_inner_io() {
+        let mut upgraded = Upgraded::new(NullIo, Bytes::from_static(b"buffered"));
+        let mut buf = [0u8; 8];
+
+        let n =
+            block_on(future::poll_fn(|cx| Pin::new(&mut upgraded).poll_read(cx, &mut buf))).unwrap();
+        assert_eq!(&buf[..n], b"buffered");
+
+        // Once the buffered bytes are drained, reads fall through to the inner IO.
+        let n =
+            block_on(future::poll_fn(|cx| Pin::new(&mut upgraded).poll_read(cx, &mut buf))).unwrap();
+        assert_eq!(n, 0);
+    }
+
+    #[test]
+    fn on_upgrade_callback_can_only_be_taken_once() {
+        let mut response = Response::new(Body::empty());
+        response.on_upgrade(|_upgraded| future::ok(()));
+
+        let on_upgrade = response.extensions().get::<OnUpgrade>().unwrap();
+        assert!(on_upgrade.take().is_some());
+        assert!(on_upgrade.take().is_none());
+    }
+
+    struct ConnMarker;
+
+    struct RecordingService;
+
+    impl HttpService for RecordingService {
+        type Connection = ConnectionInfo;
+        type ConnectionFuture = future::Ready<Result<ConnectionInfo, std::io::Error>>;
+        type Fut = future::Ready<Result<Response, std::io::Error>>;
+
+        fn connect(&self, info: &ConnectionInfo) -> Self::ConnectionFuture {
+            let mut extensions = http::Extensions::new();
+            extensions.insert(ConnMarker);
+            future::ok(ConnectionInfo {
+                peer_addr: info.peer_addr,
+                local_addr: info.local_addr,
+                extensions,
+            })
+        }
+
+        fn respond(&self, conn: &mut Self::Connection, _req: Request) -> Self::Fut {
+            assert_eq!(conn.peer_addr, Some(peer_addr()));
+            assert_eq!(conn.local_addr, Some(local_addr()));
+            assert!(conn.extensions.get::<ConnMarker>().is_some());
+            future::ok(Response::new(Body::empty()))
+        }
+    }
+
+    fn peer_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 1234)
+    }
+
+    fn local_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080)
+    }
+
+    #[test]
+    fn connect_receives_and_respond_can_read_back_the_connection_info() {
+        let service = RecordingService;
+        let info = ConnectionInfo {
+            peer_addr: Some(peer_addr()),
+            local_addr: Some(local_addr()),
+            extensions: http::Extensions::new(),
+        };
+
+        let mut conn = block_on(service.connect(&info)).unwrap();
+        block_on(service.respond(&mut conn, Request::new(Body::empty()))).unwrap();
+    }
+}
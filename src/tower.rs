@@ -0,0 +1,306 @@
+//! A bridge between [`HttpService`] and the `tower_service::Service` ecosystem.
+//!
+//! Enabled by the `tower` cargo feature. [`TowerCompat`] adapts any `HttpService`
+//! into a `tower_service::Service`, so that tower middleware (timeouts, retries,
+//! load-shedding, rate limiting, ...) can run in front of a service written
+//! against this crate. [`FromTower`] goes the other way, adapting any
+//! `tower_service::Service<Request, Response = Response>` into an `HttpService`
+//! so it can be driven by a backend built on this crate.
+
+use crate::{ConnectionInfo, HttpService, Request, Response};
+use futures::{
+    future::{self, FutureObj},
+    task::Context,
+    Future, Poll, TryFuture, TryFutureExt,
+};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use tower_service::Service as TowerService;
+
+/// The error produced by a [`TowerCompat`], distinguishing a failure to
+/// establish the connection from a failure while producing the response.
+#[derive(Debug)]
+pub enum TowerCompatError<S: HttpService> {
+    /// [`HttpService::connect`] returned an error.
+    Connect(<S::ConnectionFuture as TryFuture>::Error),
+    /// [`HttpService::respond`] returned an error.
+    Respond(<S::Fut as TryFuture>::Error),
+}
+
+/// The lazily-established connection backing a [`TowerCompat`].
+enum ConnState<S: HttpService> {
+    Idle,
+    Connecting(Pin<Box<S::ConnectionFuture>>),
+    Ready(S::Connection),
+}
+
+/// Adapts an [`HttpService`] into a `tower_service::Service`.
+///
+/// The first call to [`poll_ready`](TowerService::poll_ready) binds a connection
+/// via [`HttpService::connect`], using the [`ConnectionInfo`] supplied to
+/// [`TowerCompat::with_connection_info`] (or a default one when built with
+/// [`TowerCompat::new`]); that connection is then reused for every subsequent
+/// call. Because a `tower_service::Service` exposes no per-call connection
+/// metadata, the same `ConnectionInfo` backs every request this `TowerCompat`
+/// ever serves.
+pub struct TowerCompat<S: HttpService> {
+    service: S,
+    info: ConnectionInfo,
+    conn: Mutex<ConnState<S>>,
+}
+
+impl<S: HttpService> TowerCompat<S> {
+    /// Wrap an `HttpService` for use as a `tower_service::Service`, binding
+    /// its connection with a default [`ConnectionInfo`].
+    pub fn new(service: S) -> Self {
+        Self::with_connection_info(service, ConnectionInfo::default())
+    }
+
+    /// Wrap an `HttpService` for use as a `tower_service::Service`, binding
+    /// its connection with the given [`ConnectionInfo`].
+    pub fn with_connection_info(service: S, info: ConnectionInfo) -> Self {
+        Self {
+            service,
+            info,
+            conn: Mutex::new(ConnState::Idle),
+        }
+    }
+}
+
+impl<S: HttpService> std::fmt::Debug for TowerCompat<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TowerCompat").finish()
+    }
+}
+
+impl<S: HttpService> TowerService<Request> for TowerCompat<S> {
+    type Response = Response;
+    type Error = TowerCompatError<S>;
+    type Future = FutureObj<'static, Result<Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut conn = self.conn.lock().unwrap();
+        loop {
+            match &mut *conn {
+                ConnState::Ready(_) => return Poll::Ready(Ok(())),
+                ConnState::Idle => {
+                    let fut = self.service.connect(&self.info);
+                    *conn = ConnState::Connecting(Box::pin(fut));
+                }
+                ConnState::Connecting(fut) => match fut.as_mut().try_poll(cx) {
+                    Poll::Ready(Ok(connection)) => *conn = ConnState::Ready(connection),
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(TowerCompatError::Connect(e))),
+                    Poll::Pending => return Poll::Pending,
+                },
+            }
+        }
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let mut conn = self.conn.lock().unwrap();
+        let connection = match &mut *conn {
+            ConnState::Ready(connection) => connection,
+            ConnState::Idle | ConnState::Connecting(_) => {
+                panic!("TowerCompat::call invoked before poll_ready resolved the connection")
+            }
+        };
+        let fut = self.service.respond(connection, req);
+        FutureObj::new(Box::new(fut.into_future().map_err(TowerCompatError::Respond)))
+    }
+}
+
+/// Adapts a `tower_service::Service<Request, Response = Response>` into an
+/// [`HttpService`].
+///
+/// The resulting service carries no per-connection state (`Connection = ()`);
+/// each call to [`HttpService::respond`] waits for the inner tower service to
+/// report readiness and then forwards the request to it.
+pub struct FromTower<T> {
+    inner: Arc<Mutex<T>>,
+}
+
+impl<T> FromTower<T> {
+    /// Wrap a `tower_service::Service` for use as an `HttpService`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for FromTower<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FromTower").finish()
+    }
+}
+
+impl<T> HttpService for FromTower<T>
+where
+    T: TowerService<Request, Response = Response> + Send + 'static,
+    T::Future: Future<Output = Result<Response, T::Error>> + Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Connection = ();
+    type ConnectionFuture = future::Ready<Result<(), T::Error>>;
+
+    fn connect(&self, _info: &ConnectionInfo) -> Self::ConnectionFuture {
+        future::ok(())
+    }
+
+    type Fut = FutureObj<'static, Result<Response, T::Error>>;
+
+    fn respond(&self, _conn: &mut (), req: Request) -> Self::Fut {
+        FutureObj::new(Box::new(RespondFuture {
+            inner: self.inner.clone(),
+            state: Respond::WaitingForReady(Some(req)),
+        }))
+    }
+}
+
+/// The state of an in-flight [`FromTower::respond`] call.
+enum Respond<T: TowerService<Request>> {
+    /// Waiting on the inner service's readiness, holding the request to pass
+    /// to `call` as soon as it reports ready.
+    WaitingForReady(Option<Request>),
+    /// The inner service accepted the request via `call`; driving its future
+    /// to completion.
+    Calling(Pin<Box<T::Future>>),
+}
+
+/// The future returned by [`FromTower::respond`].
+///
+/// `poll_ready` and the `call` it authorises are both performed under the same
+/// lock acquisition, so another in-flight `respond` on the same `FromTower`
+/// can't be handed the readiness that this call was granted.
+struct RespondFuture<T: TowerService<Request>> {
+    inner: Arc<Mutex<T>>,
+    state: Respond<T>,
+}
+
+impl<T> Future for RespondFuture<T>
+where
+    T: TowerService<Request, Response = Response>,
+    T::Future: Future<Output = Result<Response, T::Error>>,
+{
+    type Output = Result<Response, T::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = Pin::into_inner(self);
+        loop {
+            match &mut this.state {
+                Respond::WaitingForReady(req) => {
+                    let mut guard = this.inner.lock().unwrap();
+                    match guard.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => {
+                            let req = req.take().expect("RespondFuture polled after completion");
+                            this.state = Respond::Calling(Box::pin(guard.call(req)));
+                        }
+                        Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+                Respond::Calling(fut) => return fut.as_mut().poll(cx),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Body;
+    use futures::executor::block_on;
+    use std::convert::Infallible;
+
+    #[derive(Clone, Debug)]
+    struct Echo;
+
+    impl TowerService<Request> for Echo {
+        type Response = Response;
+        type Error = Infallible;
+        type Future = future::Ready<Result<Response, Infallible>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: Request) -> Self::Future {
+            future::ok(Response::new(req.into_body()))
+        }
+    }
+
+    #[test]
+    fn from_tower_forwards_the_request_body_through_the_tower_service() {
+        let service = FromTower::new(Echo);
+        let req = Request::new(Body::from("ping"));
+
+        let response = block_on(service.respond(&mut (), req)).unwrap();
+        let body = block_on(response.into_body().into_string()).unwrap();
+        assert_eq!(body, "ping");
+    }
+
+    impl HttpService for Echo {
+        type Connection = ();
+        type ConnectionFuture = future::Ready<Result<(), Infallible>>;
+        type Fut = future::Ready<Result<Response, Infallible>>;
+
+        fn connect(&self, _info: &ConnectionInfo) -> Self::ConnectionFuture {
+            future::ok(())
+        }
+
+        fn respond(&self, _conn: &mut (), req: Request) -> Self::Fut {
+            future::ok(Response::new(req.into_body()))
+        }
+    }
+
+    #[test]
+    fn tower_compat_forwards_the_request_body_through_the_http_service() {
+        let mut compat = TowerCompat::new(Echo);
+
+        block_on(future::poll_fn(|cx| compat.poll_ready(cx))).unwrap();
+        let req = Request::new(Body::from("ping"));
+        let response = block_on(compat.call(req)).unwrap();
+        let body = block_on(response.into_body().into_string()).unwrap();
+        assert_eq!(body, "ping");
+    }
+
+    #[derive(Clone, Debug)]
+    struct RecordsConnectionInfo(Arc<Mutex<Option<ConnectionInfo>>>);
+
+    impl HttpService for RecordsConnectionInfo {
+        type Connection = ();
+        type ConnectionFuture = future::Ready<Result<(), Infallible>>;
+        type Fut = future::Ready<Result<Response, Infallible>>;
+
+        fn connect(&self, info: &ConnectionInfo) -> Self::ConnectionFuture {
+            *self.0.lock().unwrap() = Some(ConnectionInfo {
+                peer_addr: info.peer_addr,
+                local_addr: info.local_addr,
+                extensions: http::Extensions::new(),
+            });
+            future::ok(())
+        }
+
+        fn respond(&self, _conn: &mut (), req: Request) -> Self::Fut {
+            future::ok(Response::new(req.into_body()))
+        }
+    }
+
+    #[test]
+    fn tower_compat_binds_with_the_connection_info_it_was_constructed_with() {
+        let seen = Arc::new(Mutex::new(None));
+        let info = ConnectionInfo {
+            peer_addr: Some(([127, 0, 0, 1], 1234).into()),
+            local_addr: Some(([127, 0, 0, 1], 8080).into()),
+            extensions: http::Extensions::new(),
+        };
+        let mut compat =
+            TowerCompat::with_connection_info(RecordsConnectionInfo(seen.clone()), info);
+
+        block_on(future::poll_fn(|cx| compat.poll_ready(cx))).unwrap();
+
+        let recorded = seen.lock().unwrap().take().unwrap();
+        assert_eq!(recorded.peer_addr, Some(([127, 0, 0, 1], 1234).into()));
+        assert_eq!(recorded.local_addr, Some(([127, 0, 0, 1], 8080).into()));
+    }
+}